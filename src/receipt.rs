@@ -0,0 +1,383 @@
+//! Transaction receipts: the outcome of executing a transaction, as defined
+//! by EIP-658 (and EIP-2718 for typed transactions), plus a logs bloom
+//! filter for cheaply checking whether a receipt might contain a given log.
+
+use crate::transaction::accesslist::StorageSlot;
+use crate::transaction::rlp::{self, Item};
+use crate::transaction::TxType;
+use crate::utils::{hash, serialization};
+use anyhow::{anyhow, bail, Result};
+use ethaddr::Address;
+use ethnum::U256;
+use serde::{de, Deserialize, Deserializer};
+
+/// The number of bytes in a logs bloom filter (2048 bits).
+pub const BLOOM_BYTE_LENGTH: usize = 256;
+
+/// A 2048-bit bloom filter over the addresses and log topics emitted by a
+/// transaction's logs.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LogsBloom(pub [u8; BLOOM_BYTE_LENGTH]);
+
+impl LogsBloom {
+    /// Computes the logs bloom for a set of logs.
+    pub fn from_logs(logs: &[Log]) -> Self {
+        let mut bloom = [0u8; BLOOM_BYTE_LENGTH];
+        for log in logs {
+            Self::set(&mut bloom, &*log.address);
+            for topic in &log.topics {
+                Self::set(&mut bloom, topic);
+            }
+        }
+        Self(bloom)
+    }
+
+    /// Sets the 3 bits that `item` hashes to in `bloom`.
+    fn set(bloom: &mut [u8; BLOOM_BYTE_LENGTH], item: &[u8]) {
+        let hash = hash::keccak256(item);
+        for i in [0, 2, 4] {
+            let bit = (((hash[i] as u16) << 8 | hash[i + 1] as u16) & 0x7ff) as usize;
+            let byte = BLOOM_BYTE_LENGTH - 1 - bit / 8;
+            bloom[byte] |= 1 << (bit % 8);
+        }
+    }
+
+    /// Returns whether this bloom filter might contain `item` (an address or
+    /// a log topic). A `true` result can be a false positive; `false` is
+    /// always a true negative.
+    pub fn contains(&self, item: &[u8]) -> bool {
+        let mut probe = [0u8; BLOOM_BYTE_LENGTH];
+        Self::set(&mut probe, item);
+        probe.iter().zip(&self.0).all(|(&p, &bit)| p & bit == p)
+    }
+}
+
+/// Whether a transaction succeeded, as recorded in its receipt.
+///
+/// Pre-Byzantium receipts instead carry the post-transaction state root;
+/// EIP-658 replaced that with an explicit status bit.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ReceiptStatus {
+    /// The intermediate post-transaction state root (pre-Byzantium).
+    PostState([u8; 32]),
+    /// The EIP-658 status: `true` for success, `false` for a revert.
+    Eip658(bool),
+}
+
+/// A single log entry emitted during transaction execution.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Log {
+    /// The address that emitted the log.
+    pub address: Address,
+    /// The indexed log topics.
+    pub topics: Vec<[u8; 32]>,
+    /// The non-indexed log data.
+    pub data: Vec<u8>,
+}
+
+/// The outcome of executing a transaction.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Receipt {
+    /// Whether the transaction succeeded.
+    pub status: ReceiptStatus,
+    /// The total gas used in the block up to and including this transaction.
+    pub cumulative_gas_used: U256,
+    /// The effective gas price paid by the transaction, in Wei.
+    pub effective_gas_price: U256,
+    /// The logs emitted by the transaction.
+    pub logs: Vec<Log>,
+    /// The bloom filter over `logs`.
+    pub logs_bloom: LogsBloom,
+}
+
+impl Receipt {
+    /// Returns whether this receipt's logs bloom might contain `item` (an
+    /// address or a log topic).
+    pub fn contains(&self, item: &[u8]) -> bool {
+        self.logs_bloom.contains(item)
+    }
+
+    /// Decodes the consensus RLP fields of a receipt: `[status,
+    /// cumulativeGasUsed, logsBloom, logs]`. The effective gas price is not
+    /// part of the consensus encoding, so it is left as zero; populate it
+    /// separately (e.g. from a JSON-RPC response) if needed.
+    pub fn rlp_decode(data: &[u8]) -> Result<Self> {
+        let fields = rlp::decode_list(data)?;
+        let [status, cumulative_gas_used, logs_bloom, logs] = <[Item; 4]>::try_from(fields)
+            .map_err(|fields| anyhow!("expected 4 receipt fields, found {}", fields.len()))?;
+
+        let logs_bloom_bytes = logs_bloom.as_bytes()?;
+        let logs_bloom = LogsBloom(
+            <[u8; BLOOM_BYTE_LENGTH]>::try_from(logs_bloom_bytes)
+                .map_err(|_| anyhow!("invalid logs bloom length"))?,
+        );
+
+        Ok(Self {
+            status: decode_status(&status)?,
+            cumulative_gas_used: rlp::decode_uint(&cumulative_gas_used)?,
+            effective_gas_price: U256::ZERO,
+            logs: logs.into_list()?.into_iter().map(decode_log).collect::<Result<_>>()?,
+            logs_bloom,
+        })
+    }
+}
+
+fn decode_status(item: &Item) -> Result<ReceiptStatus> {
+    let bytes = item.as_bytes()?;
+    match bytes.len() {
+        32 => Ok(ReceiptStatus::PostState(
+            bytes.try_into().expect("length checked above"),
+        )),
+        0 => Ok(ReceiptStatus::Eip658(false)),
+        1 => Ok(ReceiptStatus::Eip658(bytes[0] != 0)),
+        len => bail!("invalid receipt status length: {len}"),
+    }
+}
+
+fn decode_log(item: Item) -> Result<Log> {
+    let fields = item.into_list()?;
+    let [address, topics, data] = <[Item; 3]>::try_from(fields)
+        .map_err(|fields| anyhow!("expected 3 log fields, found {}", fields.len()))?;
+
+    let address: [u8; 20] = address
+        .into_bytes()?
+        .try_into()
+        .map_err(|_| anyhow!("invalid log address length"))?;
+    let topics = topics
+        .into_list()?
+        .into_iter()
+        .map(|topic| {
+            topic
+                .into_bytes()?
+                .try_into()
+                .map_err(|_| anyhow!("invalid log topic length"))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(Log {
+        address: Address(address),
+        topics,
+        data: data.into_bytes()?,
+    })
+}
+
+/// A typed transaction receipt, as defined by EIP-2718.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ReceiptEnvelope {
+    /// The receipt for a legacy transaction.
+    Legacy(Receipt),
+    /// The receipt for an EIP-2930 transaction.
+    Eip2930(Receipt),
+    /// The receipt for an EIP-1559 transaction.
+    Eip1559(Receipt),
+    /// The receipt for an EIP-4844 transaction.
+    Eip4844(Receipt),
+}
+
+impl ReceiptEnvelope {
+    /// Returns the EIP-2718 type of the transaction this receipt belongs to.
+    pub fn tx_type(&self) -> TxType {
+        match self {
+            Self::Legacy(_) => TxType::Legacy,
+            Self::Eip2930(_) => TxType::Eip2930,
+            Self::Eip1559(_) => TxType::Eip1559,
+            Self::Eip4844(_) => TxType::Eip4844,
+        }
+    }
+
+    /// Returns the inner receipt, regardless of transaction type.
+    pub fn receipt(&self) -> &Receipt {
+        match self {
+            Self::Legacy(receipt)
+            | Self::Eip2930(receipt)
+            | Self::Eip1559(receipt)
+            | Self::Eip4844(receipt) => receipt,
+        }
+    }
+
+    /// Returns the effective gas price paid by the transaction, in Wei.
+    pub fn effective_gas_price(&self) -> U256 {
+        self.receipt().effective_gas_price
+    }
+
+    /// Returns whether this receipt's logs bloom might contain `item` (an
+    /// address or a log topic).
+    pub fn contains(&self, item: &[u8]) -> bool {
+        self.receipt().contains(item)
+    }
+
+    /// Parses a raw receipt off the wire, picking the variant based on its
+    /// EIP-2718 type byte (or the absence of one, for legacy receipts).
+    pub fn rlp_decode(data: &[u8]) -> Result<Self> {
+        match data.first() {
+            Some(0x01) => Ok(Self::Eip2930(Receipt::rlp_decode(&data[1..])?)),
+            Some(0x02) => Ok(Self::Eip1559(Receipt::rlp_decode(&data[1..])?)),
+            Some(0x03) => Ok(Self::Eip4844(Receipt::rlp_decode(&data[1..])?)),
+            Some(&prefix) if prefix >= 0xc0 => Ok(Self::Legacy(Receipt::rlp_decode(data)?)),
+            _ => Err(anyhow!("unrecognized receipt envelope")),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawReceipt {
+    #[serde(default, with = "ethnum::serde::permissive")]
+    cumulative_gas_used: U256,
+    #[serde(default, with = "ethnum::serde::permissive")]
+    effective_gas_price: U256,
+    #[serde(default)]
+    status: Option<String>,
+    #[serde(default)]
+    root: Option<String>,
+    #[serde(with = "serialization::bytes")]
+    logs_bloom: Vec<u8>,
+    logs: Vec<RawLog>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawLog {
+    address: Address,
+    topics: Vec<StorageSlot>,
+    #[serde(with = "serialization::bytes")]
+    data: Vec<u8>,
+}
+
+impl<'de> Deserialize<'de> for ReceiptEnvelope {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        // The `type` field (EIP-2718) picks which variant this is; receipts
+        // without one are treated as legacy.
+        let value = serde_json::Value::deserialize(deserializer)?;
+        let tx_type = match value.get("type") {
+            Some(serde_json::Value::String(s)) => {
+                u64::from_str_radix(s.trim_start_matches("0x"), 16)
+                    .map_err(|_| de::Error::custom(format!("invalid receipt type {s:?}")))?
+            }
+            Some(serde_json::Value::Number(n)) => n
+                .as_u64()
+                .ok_or_else(|| de::Error::custom("invalid receipt type"))?,
+            Some(other) => return Err(de::Error::custom(format!("invalid receipt type {other:?}"))),
+            None => 0,
+        };
+
+        let raw = RawReceipt::deserialize(value).map_err(de::Error::custom)?;
+        let status = match (raw.status, raw.root) {
+            (Some(status), _) => {
+                let status = u64::from_str_radix(status.trim_start_matches("0x"), 16)
+                    .map_err(|_| de::Error::custom("invalid receipt status"))?;
+                ReceiptStatus::Eip658(status != 0)
+            }
+            (None, Some(root)) => {
+                let bytes = parse_hex(&root).map_err(de::Error::custom)?;
+                let root: [u8; 32] = bytes
+                    .try_into()
+                    .map_err(|_| de::Error::custom("invalid receipt root length"))?;
+                ReceiptStatus::PostState(root)
+            }
+            (None, None) => {
+                return Err(de::Error::custom("receipt is missing both status and root"))
+            }
+        };
+
+        let logs_bloom = <[u8; BLOOM_BYTE_LENGTH]>::try_from(raw.logs_bloom.as_slice())
+            .map_err(|_| de::Error::custom("invalid logsBloom length"))?;
+        let logs = raw
+            .logs
+            .into_iter()
+            .map(|log| Log {
+                address: log.address,
+                topics: log.topics.into_iter().map(|topic| topic.0).collect(),
+                data: log.data,
+            })
+            .collect();
+
+        let receipt = Receipt {
+            status,
+            cumulative_gas_used: raw.cumulative_gas_used,
+            effective_gas_price: raw.effective_gas_price,
+            logs,
+            logs_bloom: LogsBloom(logs_bloom),
+        };
+
+        match tx_type {
+            0 => Ok(Self::Legacy(receipt)),
+            1 => Ok(Self::Eip2930(receipt)),
+            2 => Ok(Self::Eip1559(receipt)),
+            3 => Ok(Self::Eip4844(receipt)),
+            other => Err(de::Error::custom(format!(
+                "unsupported receipt type {other:#x}"
+            ))),
+        }
+    }
+}
+
+/// Parses a `0x`-prefixed (or bare) hex string into bytes.
+fn parse_hex(s: &str) -> Result<Vec<u8>> {
+    let s = s.strip_prefix("0x").unwrap_or(s);
+    if s.len() % 2 != 0 {
+        bail!("odd-length hex string");
+    }
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).map_err(|e| anyhow!(e)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ethaddr::address;
+    use ethnum::AsU256 as _;
+
+    fn sample_log() -> Log {
+        Log {
+            address: address!("0xDeaDbeefdEAdbeefdEadbEEFdeadbeEFdEaDbeeF"),
+            topics: vec![[0x11; 32]],
+            data: vec![0xab, 0xcd],
+        }
+    }
+
+    #[test]
+    fn logs_bloom_contains_what_it_was_built_from() {
+        let log = sample_log();
+        let bloom = LogsBloom::from_logs(&[log.clone()]);
+
+        assert!(bloom.contains(&*log.address));
+        assert!(bloom.contains(&log.topics[0]));
+        assert!(!bloom.contains(&[0x99; 32]));
+    }
+
+    #[test]
+    fn rlp_decode_round_trips_a_receipt() {
+        let log = sample_log();
+        let logs_bloom = LogsBloom::from_logs(&[log.clone()]);
+        let fields = [
+            rlp::uint(1.as_u256()),
+            rlp::uint(21_000.as_u256()),
+            rlp::bytes(&logs_bloom.0),
+            rlp::iter(
+                [rlp::iter(
+                    [
+                        rlp::bytes(&*log.address),
+                        rlp::iter([rlp::bytes(&log.topics[0])].iter()),
+                        rlp::bytes(&log.data),
+                    ]
+                    .iter(),
+                )]
+                .iter(),
+            ),
+        ];
+        let encoded = rlp::iter(fields.iter());
+
+        let receipt = Receipt::rlp_decode(&encoded).unwrap();
+        assert_eq!(receipt.status, ReceiptStatus::Eip658(true));
+        assert_eq!(receipt.cumulative_gas_used, 21_000.as_u256());
+        assert_eq!(receipt.logs, vec![log.clone()]);
+        assert_eq!(receipt.logs_bloom, logs_bloom);
+        assert!(receipt.contains(&*log.address));
+    }
+}
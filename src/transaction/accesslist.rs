@@ -0,0 +1,82 @@
+//! The EIP-2930 access list.
+
+use super::rlp::{self, Item};
+use crate::utils::serialization;
+use anyhow::{anyhow, bail, Result};
+use ethaddr::Address;
+use serde::{Deserialize, Deserializer};
+
+/// A list of addresses and storage keys that a transaction plans to access,
+/// as defined by EIP-2930.
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq)]
+#[serde(transparent)]
+pub struct AccessList(pub Vec<(Address, Vec<StorageSlot>)>);
+
+impl AccessList {
+    /// Returns the RLP encoding of the access list.
+    pub fn rlp_encode(&self) -> Vec<u8> {
+        let entries: Vec<Vec<u8>> = self
+            .0
+            .iter()
+            .map(|(address, slots)| {
+                let keys: Vec<Vec<u8>> = slots.iter().map(|slot| rlp::bytes(&slot.0)).collect();
+                let fields = [rlp::bytes(&**address), rlp::iter(keys.iter())];
+                rlp::iter(fields.iter())
+            })
+            .collect();
+
+        rlp::iter(entries.iter())
+    }
+
+    /// Inverts [`AccessList::rlp_encode`], decoding an access list from its
+    /// already-parsed RLP item.
+    pub fn rlp_decode(item: &Item) -> Result<Self> {
+        let entries = item
+            .clone()
+            .into_list()?
+            .into_iter()
+            .map(|entry| {
+                let mut fields = entry.into_list()?;
+                if fields.len() != 2 {
+                    bail!("invalid access list entry: expected 2 fields");
+                }
+                let keys = fields.remove(1).into_list()?;
+                let address = fields.remove(0).into_bytes()?;
+
+                let address: [u8; 20] = address
+                    .try_into()
+                    .map_err(|_| anyhow!("invalid access list address length"))?;
+                let keys = keys
+                    .into_iter()
+                    .map(|key| {
+                        let key = key.into_bytes()?;
+                        let slot: [u8; 32] = key
+                            .try_into()
+                            .map_err(|_| anyhow!("invalid storage slot length"))?;
+                        Ok(StorageSlot(slot))
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+
+                Ok((Address(address), keys))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self(entries))
+    }
+}
+
+/// A single 32-byte storage key within an access list entry.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct StorageSlot(pub [u8; 32]);
+
+impl<'de> Deserialize<'de> for StorageSlot {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let bytes = serialization::bytes::deserialize(deserializer)?;
+        let slot = <[u8; 32]>::try_from(bytes.as_slice())
+            .map_err(|_| serde::de::Error::custom("storage slot must be 32 bytes"))?;
+        Ok(Self(slot))
+    }
+}
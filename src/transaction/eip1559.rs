@@ -1,12 +1,10 @@
-//! Module defining Ethereum transaction data as well as an RLP encoding
-//! implementation.
+//! EIP-1559 Ethereum transactions.
 
-pub mod accesslist;
-mod rlp;
-
-use crate::utils::hash;
-use crate::{transaction::accesslist::AccessList, utils::serialization, wallet::Signature};
-use anyhow::Result;
+use super::accesslist::AccessList;
+use super::rlp;
+use crate::utils::{hash, serialization};
+use crate::wallet::{Signature, Wallet};
+use anyhow::{anyhow, bail, Result};
 use ethaddr::Address;
 use ethnum::U256;
 use serde::Deserialize;
@@ -14,7 +12,7 @@ use serde::Deserialize;
 /// An EIP-1559 Ethereum transaction.
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Default)]
 #[serde(rename_all = "camelCase")]
-pub struct Transaction {
+pub struct TxEip1559 {
     /// The chain ID for the transaction.
     #[serde(with = "ethnum::serde::permissive")]
     pub chain_id: U256,
@@ -52,9 +50,9 @@ pub struct Transaction {
     pub access_list: AccessList,
 }
 
-impl Transaction {
+impl TxEip1559 {
     // Sign with a wallet.
-    pub fn sign_with_wallet(&mut self, wallet: &crate::wallet::Wallet) -> Result<Vec<u8>> {
+    pub fn sign_with_wallet(&mut self, wallet: &Wallet) -> Result<Vec<u8>> {
         let message = self.get_unsigned_rlp_encoded();
         let signature = wallet.sign(message)?;
         let encoded = self.get_signed_rlp_encoded(signature);
@@ -72,6 +70,17 @@ impl Transaction {
         self.rlp_encode(Some(signature))
     }
 
+    /// Recovers the address that produced `signature` over this transaction.
+    pub fn recover_signer(&self, signature: &Signature) -> Result<Address> {
+        crate::utils::ecrecover(
+            self.get_unsigned_rlp_encoded(),
+            signature.r(),
+            signature.s(),
+            signature.y_parity(),
+        )
+        .map(|address| Address(address.0))
+    }
+
     /// Returns the RLP encoded transaction with an optional signature.
     pub fn rlp_encode(&self, signature: Option<Signature>) -> Vec<u8> {
         let fields = [
@@ -102,6 +111,40 @@ impl Transaction {
         ]
         .concat()
     }
+
+    /// Inverts [`TxEip1559::rlp_encode`], parsing a raw `0x02`-prefixed
+    /// transaction back into its fields and signature.
+    pub fn rlp_decode(data: &[u8]) -> Result<(Self, Option<Signature>)> {
+        let data = data
+            .strip_prefix(&[0x02])
+            .ok_or_else(|| anyhow!("not an EIP-1559 transaction"))?;
+        let items = rlp::decode_list(data)?;
+        let (fields, signature) = match items.len() {
+            9 => (items, None),
+            12 => {
+                let (fields, tail) = items.split_at(9);
+                let y_parity = rlp::decode_uint(&tail[0])?;
+                let r = rlp::decode_uint(&tail[1])?;
+                let s = rlp::decode_uint(&tail[2])?;
+                (fields.to_vec(), Some(Signature::new(y_parity, r, s)))
+            }
+            n => bail!("unexpected number of RLP fields for an EIP-1559 transaction: {n}"),
+        };
+
+        let tx = Self {
+            chain_id: rlp::decode_uint(&fields[0])?,
+            nonce: rlp::decode_uint(&fields[1])?,
+            max_priority_fee_per_gas: rlp::decode_uint(&fields[2])?,
+            max_fee_per_gas: rlp::decode_uint(&fields[3])?,
+            gas: rlp::decode_uint(&fields[4])?,
+            to: rlp::decode_address(&fields[5])?,
+            value: rlp::decode_uint(&fields[6])?,
+            data: fields[7].as_bytes()?.to_vec(),
+            access_list: AccessList::rlp_decode(&fields[8])?,
+        };
+
+        Ok((tx, signature))
+    }
 }
 
 #[cfg(test)]
@@ -118,7 +161,7 @@ mod tests {
         hex!("4f3edf983ac636a65a842ce7c78d9aa706d3b113bce9c46f30d7d21715b23b1d");
 
     fn sign_encode(tx: Value) -> Vec<u8> {
-        let tx = serde_json::from_value::<Transaction>(tx).unwrap();
+        let tx = serde_json::from_value::<TxEip1559>(tx).unwrap();
         let wallet = Wallet::from_secret(DETERMINISTIC_PRIVATE_KEY).unwrap();
         let signature = wallet.sign(tx.get_unsigned_rlp_encoded()).unwrap();
         tx.get_signed_rlp_encoded(signature)
@@ -158,8 +201,8 @@ mod tests {
             "data": "0x",
         });
         assert_eq!(
-            serde_json::from_value::<Transaction>(tx.clone()).unwrap(),
-            Transaction {
+            serde_json::from_value::<TxEip1559>(tx.clone()).unwrap(),
+            TxEip1559 {
                 chain_id: 255.as_u256(),
                 nonce: 42.as_u256(),
                 max_priority_fee_per_gas: 13.37e9.as_u256(),
@@ -177,7 +220,7 @@ mod tests {
             "0x0000000000000000000000000000000000000000",
             ["0x0000000000000000000000000000000000000000000000000000000000000000",],
         ]]);
-        let deserialized = serde_json::from_value::<Transaction>(tx).unwrap();
+        let deserialized = serde_json::from_value::<TxEip1559>(tx).unwrap();
         assert_eq!(
             deserialized.to.unwrap(),
             address!("0xDeaDbeefdEAdbeefdEadbEEFdeadbeEFdEaDbeeF"),
@@ -191,7 +234,7 @@ mod tests {
     #[test]
     fn encode() {
         assert_eq!(
-            Transaction {
+            TxEip1559 {
                 chain_id: 1.as_u256(),
                 nonce: 66.as_u256(),
                 max_priority_fee_per_gas: 28e9.as_u256(),
@@ -210,7 +253,7 @@ mod tests {
             .to_owned(),
         );
         assert_eq!(
-            Transaction {
+            TxEip1559 {
                 chain_id: 1.as_u256(),
                 nonce: 777.as_u256(),
                 max_priority_fee_per_gas: 28e9.as_u256(),
@@ -253,4 +296,56 @@ mod tests {
             .to_vec(),
         );
     }
+
+    #[test]
+    fn decode_round_trips_with_encode() {
+        let tx = TxEip1559 {
+            chain_id: 1.as_u256(),
+            nonce: 66.as_u256(),
+            max_priority_fee_per_gas: 28e9.as_u256(),
+            max_fee_per_gas: 42e9.as_u256(),
+            gas: 30_000.as_u256(),
+            to: Some(address!("0xDeaDbeefdEAdbeefdEadbEEFdeadbeEFdEaDbeeF")),
+            value: 13.37e18.as_u256(),
+            data: vec![],
+            access_list: AccessList(vec![(
+                address!("0x1111111111111111111111111111111111111111"),
+                vec![StorageSlot(hex!(
+                    "a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0"
+                ))],
+            )]),
+        };
+
+        let (decoded, signature) = TxEip1559::rlp_decode(&tx.rlp_encode(None)).unwrap();
+        assert_eq!(decoded, tx);
+        assert_eq!(signature, None);
+
+        let wallet = Wallet::from_secret(DETERMINISTIC_PRIVATE_KEY).unwrap();
+        let signature = wallet.sign(tx.get_unsigned_rlp_encoded()).unwrap();
+        let (decoded, decoded_signature) =
+            TxEip1559::rlp_decode(&tx.get_signed_rlp_encoded(signature)).unwrap();
+        assert_eq!(decoded, tx);
+        assert_eq!(decoded_signature, Some(signature));
+    }
+
+    #[test]
+    fn sign_then_recover_signer_round_trips() {
+        let tx = TxEip1559 {
+            chain_id: 1.as_u256(),
+            nonce: 66.as_u256(),
+            max_priority_fee_per_gas: 28e9.as_u256(),
+            max_fee_per_gas: 42e9.as_u256(),
+            gas: 30_000.as_u256(),
+            to: Some(address!("0xDeaDbeefdEAdbeefdEadbEEFdeadbeEFdEaDbeeF")),
+            value: 13.37e18.as_u256(),
+            data: vec![],
+            access_list: AccessList::default(),
+        };
+        let wallet = Wallet::from_secret(DETERMINISTIC_PRIVATE_KEY).unwrap();
+        let signature = wallet.sign(tx.get_unsigned_rlp_encoded()).unwrap();
+
+        let recovered = tx.recover_signer(&signature).unwrap();
+        let expected = crate::utils::address_from_pk(DETERMINISTIC_PRIVATE_KEY).unwrap();
+        assert_eq!(*recovered, expected.0);
+    }
 }
@@ -0,0 +1,199 @@
+//! EIP-2930 Ethereum transactions (optional access lists).
+
+use super::accesslist::AccessList;
+use super::rlp;
+use crate::utils::{hash, serialization};
+use crate::wallet::{Signature, Wallet};
+use anyhow::{anyhow, bail, Result};
+use ethaddr::Address;
+use ethnum::U256;
+use serde::Deserialize;
+
+/// An EIP-2930 Ethereum transaction.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct TxEip2930 {
+    /// The chain ID for the transaction.
+    #[serde(with = "ethnum::serde::permissive")]
+    pub chain_id: U256,
+
+    /// The nonce for the transaction.
+    #[serde(with = "ethnum::serde::permissive")]
+    pub nonce: U256,
+
+    /// The gas price in Wei for the transaction.
+    #[serde(with = "ethnum::serde::permissive")]
+    pub gas_price: U256,
+
+    /// The gas limit for the transaction.
+    #[serde(with = "ethnum::serde::permissive")]
+    pub gas: U256,
+
+    /// The target address for the transaction. This can also be `None` to
+    /// indicate a contract creation transaction.
+    pub to: Option<Address>,
+
+    /// The amount of Ether to send with the transaction.
+    #[serde(with = "ethnum::serde::permissive")]
+    pub value: U256,
+
+    /// The calldata to use for the transaction.
+    #[serde(with = "serialization::bytes")]
+    pub data: Vec<u8>,
+
+    /// List of addresses and storage keys that the transaction plans to access.
+    #[serde(default)]
+    pub access_list: AccessList,
+}
+
+impl TxEip2930 {
+    // Sign with a wallet.
+    pub fn sign_with_wallet(&mut self, wallet: &Wallet) -> Result<Vec<u8>> {
+        let message = self.get_unsigned_rlp_encoded();
+        let signature = wallet.sign(message)?;
+        let encoded = self.get_signed_rlp_encoded(signature);
+
+        Ok(encoded)
+    }
+
+    /// Returns the RLP encoded transaction without signature.
+    pub fn get_unsigned_rlp_encoded(&self) -> [u8; 32] {
+        hash::keccak256(self.rlp_encode(None))
+    }
+
+    /// Returns 32-byte message used for signing.
+    pub fn get_signed_rlp_encoded(&self, signature: Signature) -> Vec<u8> {
+        self.rlp_encode(Some(signature))
+    }
+
+    /// Recovers the address that produced `signature` over this transaction.
+    pub fn recover_signer(&self, signature: &Signature) -> Result<Address> {
+        crate::utils::ecrecover(
+            self.get_unsigned_rlp_encoded(),
+            signature.r(),
+            signature.s(),
+            signature.y_parity(),
+        )
+        .map(|address| Address(address.0))
+    }
+
+    /// Returns the RLP encoded transaction with an optional signature.
+    pub fn rlp_encode(&self, signature: Option<Signature>) -> Vec<u8> {
+        let fields = [
+            rlp::uint(self.chain_id),
+            rlp::uint(self.nonce),
+            rlp::uint(self.gas_price),
+            rlp::uint(self.gas),
+            self.to
+                .map_or_else(|| rlp::bytes(b""), |to| rlp::bytes(&*to)),
+            rlp::uint(self.value),
+            rlp::bytes(&self.data),
+            self.access_list.rlp_encode(),
+        ];
+
+        // EIP-2930 signatures carry a bare `yParity` (0/1); unlike legacy
+        // transactions there is no chain-id folding into `v`.
+        let tail = signature.map(|signature| {
+            [
+                rlp::uint(signature.y_parity()),
+                rlp::uint(signature.r()),
+                rlp::uint(signature.s()),
+            ]
+        });
+
+        // Add the header for EIP-2930 transactions. Based on EIP-2718.
+        [
+            &[0x01][..],
+            &rlp::iter(fields.iter().chain(tail.iter().flatten())),
+        ]
+        .concat()
+    }
+
+    /// Inverts [`TxEip2930::rlp_encode`], parsing a raw `0x01`-prefixed
+    /// transaction back into its fields and signature.
+    pub fn rlp_decode(data: &[u8]) -> Result<(Self, Option<Signature>)> {
+        let data = data
+            .strip_prefix(&[0x01])
+            .ok_or_else(|| anyhow!("not an EIP-2930 transaction"))?;
+        let items = rlp::decode_list(data)?;
+        let (fields, signature) = match items.len() {
+            8 => (items, None),
+            11 => {
+                let (fields, tail) = items.split_at(8);
+                let y_parity = rlp::decode_uint(&tail[0])?;
+                let r = rlp::decode_uint(&tail[1])?;
+                let s = rlp::decode_uint(&tail[2])?;
+                (fields.to_vec(), Some(Signature::new(y_parity, r, s)))
+            }
+            n => bail!("unexpected number of RLP fields for an EIP-2930 transaction: {n}"),
+        };
+
+        let tx = Self {
+            chain_id: rlp::decode_uint(&fields[0])?,
+            nonce: rlp::decode_uint(&fields[1])?,
+            gas_price: rlp::decode_uint(&fields[2])?,
+            gas: rlp::decode_uint(&fields[3])?,
+            to: rlp::decode_address(&fields[4])?,
+            value: rlp::decode_uint(&fields[5])?,
+            data: fields[6].as_bytes()?.to_vec(),
+            access_list: AccessList::rlp_decode(&fields[7])?,
+        };
+
+        Ok((tx, signature))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wallet::Wallet;
+    use ethaddr::address;
+    use ethnum::AsU256 as _;
+    use hex_literal::hex;
+
+    const DETERMINISTIC_PRIVATE_KEY: [u8; 32] =
+        hex!("4f3edf983ac636a65a842ce7c78d9aa706d3b113bce9c46f30d7d21715b23b1d");
+
+    fn sample() -> TxEip2930 {
+        TxEip2930 {
+            chain_id: 1.as_u256(),
+            nonce: 66.as_u256(),
+            gas_price: 42e9.as_u256(),
+            gas: 30_000.as_u256(),
+            to: Some(address!("0xDeaDbeefdEAdbeefdEadbEEFdeadbeEFdEaDbeeF")),
+            value: 13.37e18.as_u256(),
+            data: vec![],
+            access_list: AccessList(vec![(
+                address!("0x0000000000000000000000000000000000000001"),
+                vec![],
+            )]),
+        }
+    }
+
+    #[test]
+    fn decode_round_trips_with_encode() {
+        let tx = sample();
+
+        let (decoded, signature) = TxEip2930::rlp_decode(&tx.rlp_encode(None)).unwrap();
+        assert_eq!(decoded, tx);
+        assert_eq!(signature, None);
+
+        let wallet = Wallet::from_secret(DETERMINISTIC_PRIVATE_KEY).unwrap();
+        let signature = wallet.sign(tx.get_unsigned_rlp_encoded()).unwrap();
+        let (decoded, decoded_signature) =
+            TxEip2930::rlp_decode(&tx.get_signed_rlp_encoded(signature)).unwrap();
+        assert_eq!(decoded, tx);
+        assert_eq!(decoded_signature, Some(signature));
+    }
+
+    #[test]
+    fn sign_then_recover_signer_round_trips() {
+        let tx = sample();
+        let wallet = Wallet::from_secret(DETERMINISTIC_PRIVATE_KEY).unwrap();
+        let signature = wallet.sign(tx.get_unsigned_rlp_encoded()).unwrap();
+
+        let recovered = tx.recover_signer(&signature).unwrap();
+        let expected = crate::utils::address_from_pk(DETERMINISTIC_PRIVATE_KEY).unwrap();
+        assert_eq!(*recovered, expected.0);
+    }
+}
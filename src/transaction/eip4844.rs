@@ -0,0 +1,261 @@
+//! EIP-4844 blob-carrying Ethereum transactions.
+
+use super::accesslist::AccessList;
+use super::rlp;
+use crate::utils::{hash, serialization};
+use crate::wallet::{Signature, Wallet};
+use anyhow::{anyhow, bail, Result};
+use ethaddr::Address;
+use ethnum::U256;
+use serde::Deserialize;
+
+/// An EIP-4844 blob transaction.
+///
+/// Only the consensus fields - those covered by the signature - are
+/// represented here. The blob sidecar (the blobs themselves, along with
+/// their KZG commitments and proofs) is carried out-of-band in
+/// [`BlobSidecar`] and is never part of the RLP encoding in this module.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct TxEip4844 {
+    /// The chain ID for the transaction.
+    #[serde(with = "ethnum::serde::permissive")]
+    pub chain_id: U256,
+
+    /// The nonce for the transaction.
+    #[serde(with = "ethnum::serde::permissive")]
+    pub nonce: U256,
+
+    /// The maximum priority fee in Wei for the transaction.
+    #[serde(with = "ethnum::serde::permissive")]
+    pub max_priority_fee_per_gas: U256,
+
+    /// The maximum gas price in Wei for the transaction.
+    #[serde(with = "ethnum::serde::permissive")]
+    pub max_fee_per_gas: U256,
+
+    /// The gas limit for the transaction.
+    #[serde(with = "ethnum::serde::permissive")]
+    pub gas: U256,
+
+    /// The target address for the transaction. Unlike other transaction
+    /// types, contract creation is disallowed, so this is mandatory.
+    pub to: Address,
+
+    /// The amount of Ether to send with the transaction.
+    #[serde(with = "ethnum::serde::permissive")]
+    pub value: U256,
+
+    /// The calldata to use for the transaction.
+    #[serde(with = "serialization::bytes")]
+    pub data: Vec<u8>,
+
+    /// List of addresses and storage keys that the transaction plans to access.
+    #[serde(default)]
+    pub access_list: AccessList,
+
+    /// The maximum fee per unit of blob gas in Wei for the transaction.
+    #[serde(with = "ethnum::serde::permissive")]
+    pub max_fee_per_blob_gas: U256,
+
+    /// The versioned hashes of the blobs carried by this transaction.
+    #[serde(default)]
+    pub blob_versioned_hashes: Vec<[u8; 32]>,
+
+    /// The network-form sidecar data for this transaction. This is not part
+    /// of the consensus RLP encoding and is never signed over; it is only
+    /// needed when broadcasting the transaction over the wire.
+    #[serde(skip)]
+    pub sidecar: Option<BlobSidecar>,
+}
+
+impl TxEip4844 {
+    // Sign with a wallet.
+    pub fn sign_with_wallet(&mut self, wallet: &Wallet) -> Result<Vec<u8>> {
+        let message = self.get_unsigned_rlp_encoded();
+        let signature = wallet.sign(message)?;
+        let encoded = self.get_signed_rlp_encoded(signature);
+
+        Ok(encoded)
+    }
+
+    /// Returns the RLP encoded transaction without signature.
+    pub fn get_unsigned_rlp_encoded(&self) -> [u8; 32] {
+        hash::keccak256(self.rlp_encode(None))
+    }
+
+    /// Returns 32-byte message used for signing.
+    pub fn get_signed_rlp_encoded(&self, signature: Signature) -> Vec<u8> {
+        self.rlp_encode(Some(signature))
+    }
+
+    /// Recovers the address that produced `signature` over this transaction.
+    pub fn recover_signer(&self, signature: &Signature) -> Result<Address> {
+        crate::utils::ecrecover(
+            self.get_unsigned_rlp_encoded(),
+            signature.r(),
+            signature.s(),
+            signature.y_parity(),
+        )
+        .map(|address| Address(address.0))
+    }
+
+    /// Returns the RLP encoded transaction with an optional signature.
+    pub fn rlp_encode(&self, signature: Option<Signature>) -> Vec<u8> {
+        let blob_versioned_hashes: Vec<Vec<u8>> = self
+            .blob_versioned_hashes
+            .iter()
+            .map(|hash| rlp::bytes(hash))
+            .collect();
+
+        let fields = [
+            rlp::uint(self.chain_id),
+            rlp::uint(self.nonce),
+            rlp::uint(self.max_priority_fee_per_gas),
+            rlp::uint(self.max_fee_per_gas),
+            rlp::uint(self.gas),
+            rlp::bytes(&*self.to),
+            rlp::uint(self.value),
+            rlp::bytes(&self.data),
+            self.access_list.rlp_encode(),
+            rlp::uint(self.max_fee_per_blob_gas),
+            rlp::iter(blob_versioned_hashes.iter()),
+        ];
+
+        let tail = signature.map(|signature| {
+            [
+                rlp::uint(signature.y_parity()),
+                rlp::uint(signature.r()),
+                rlp::uint(signature.s()),
+            ]
+        });
+
+        // Add the header for EIP-4844 transactions. Based on EIP-2718.
+        [
+            &[0x03][..],
+            &rlp::iter(fields.iter().chain(tail.iter().flatten())),
+        ]
+        .concat()
+    }
+
+    /// Inverts [`TxEip4844::rlp_encode`], parsing a raw `0x03`-prefixed
+    /// transaction back into its fields and signature.
+    pub fn rlp_decode(data: &[u8]) -> Result<(Self, Option<Signature>)> {
+        let data = data
+            .strip_prefix(&[0x03])
+            .ok_or_else(|| anyhow!("not an EIP-4844 transaction"))?;
+        let items = rlp::decode_list(data)?;
+        let (fields, signature) = match items.len() {
+            11 => (items, None),
+            14 => {
+                let (fields, tail) = items.split_at(11);
+                let y_parity = rlp::decode_uint(&tail[0])?;
+                let r = rlp::decode_uint(&tail[1])?;
+                let s = rlp::decode_uint(&tail[2])?;
+                (fields.to_vec(), Some(Signature::new(y_parity, r, s)))
+            }
+            n => bail!("unexpected number of RLP fields for an EIP-4844 transaction: {n}"),
+        };
+
+        let to = rlp::decode_address(&fields[5])?
+            .ok_or_else(|| anyhow!("EIP-4844 transactions cannot be contract creations"))?;
+        let blob_versioned_hashes = fields[10]
+            .clone()
+            .into_list()?
+            .into_iter()
+            .map(|item| {
+                let bytes = item.into_bytes()?;
+                <[u8; 32]>::try_from(bytes.as_slice())
+                    .map_err(|_| anyhow!("invalid blob versioned hash length"))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let tx = Self {
+            chain_id: rlp::decode_uint(&fields[0])?,
+            nonce: rlp::decode_uint(&fields[1])?,
+            max_priority_fee_per_gas: rlp::decode_uint(&fields[2])?,
+            max_fee_per_gas: rlp::decode_uint(&fields[3])?,
+            gas: rlp::decode_uint(&fields[4])?,
+            to,
+            value: rlp::decode_uint(&fields[6])?,
+            data: fields[7].as_bytes()?.to_vec(),
+            access_list: AccessList::rlp_decode(&fields[8])?,
+            max_fee_per_blob_gas: rlp::decode_uint(&fields[9])?,
+            blob_versioned_hashes,
+            sidecar: None,
+        };
+
+        Ok((tx, signature))
+    }
+}
+
+/// The network-form sidecar (blobs, KZG commitments, and proofs) that
+/// accompanies an EIP-4844 transaction when broadcasting it, but which is
+/// kept out of the consensus RLP encoding covered by the signature.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct BlobSidecar {
+    /// The raw blob data.
+    pub blobs: Vec<Vec<u8>>,
+    /// The KZG commitment for each blob.
+    pub commitments: Vec<[u8; 48]>,
+    /// The KZG proof for each blob.
+    pub proofs: Vec<[u8; 48]>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wallet::Wallet;
+    use ethaddr::address;
+    use ethnum::AsU256 as _;
+    use hex_literal::hex;
+
+    const DETERMINISTIC_PRIVATE_KEY: [u8; 32] =
+        hex!("4f3edf983ac636a65a842ce7c78d9aa706d3b113bce9c46f30d7d21715b23b1d");
+
+    fn sample() -> TxEip4844 {
+        TxEip4844 {
+            chain_id: 1.as_u256(),
+            nonce: 66.as_u256(),
+            max_priority_fee_per_gas: 28e9.as_u256(),
+            max_fee_per_gas: 42e9.as_u256(),
+            gas: 30_000.as_u256(),
+            to: address!("0xDeaDbeefdEAdbeefdEadbEEFdeadbeEFdEaDbeeF"),
+            value: 13.37e18.as_u256(),
+            data: vec![],
+            access_list: AccessList::default(),
+            max_fee_per_blob_gas: 1e9.as_u256(),
+            blob_versioned_hashes: vec![hex!(
+                "01a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0"
+            )],
+            sidecar: None,
+        }
+    }
+
+    #[test]
+    fn decode_round_trips_with_encode() {
+        let tx = sample();
+
+        let (decoded, signature) = TxEip4844::rlp_decode(&tx.rlp_encode(None)).unwrap();
+        assert_eq!(decoded, tx);
+        assert_eq!(signature, None);
+
+        let wallet = Wallet::from_secret(DETERMINISTIC_PRIVATE_KEY).unwrap();
+        let signature = wallet.sign(tx.get_unsigned_rlp_encoded()).unwrap();
+        let (decoded, decoded_signature) =
+            TxEip4844::rlp_decode(&tx.get_signed_rlp_encoded(signature)).unwrap();
+        assert_eq!(decoded, tx);
+        assert_eq!(decoded_signature, Some(signature));
+    }
+
+    #[test]
+    fn sign_then_recover_signer_round_trips() {
+        let tx = sample();
+        let wallet = Wallet::from_secret(DETERMINISTIC_PRIVATE_KEY).unwrap();
+        let signature = wallet.sign(tx.get_unsigned_rlp_encoded()).unwrap();
+
+        let recovered = tx.recover_signer(&signature).unwrap();
+        let expected = crate::utils::address_from_pk(DETERMINISTIC_PRIVATE_KEY).unwrap();
+        assert_eq!(*recovered, expected.0);
+    }
+}
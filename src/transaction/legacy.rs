@@ -0,0 +1,234 @@
+//! Legacy (pre-EIP-2718) Ethereum transactions.
+
+use super::rlp;
+use crate::utils::{hash, serialization};
+use crate::wallet::{Signature, Wallet};
+use anyhow::{bail, Result};
+use ethaddr::Address;
+use ethnum::U256;
+use serde::Deserialize;
+
+/// A legacy Ethereum transaction, signed with EIP-155 replay protection.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct TxLegacy {
+    /// The chain ID used for EIP-155 replay protection.
+    #[serde(with = "ethnum::serde::permissive")]
+    pub chain_id: U256,
+
+    /// The nonce for the transaction.
+    #[serde(with = "ethnum::serde::permissive")]
+    pub nonce: U256,
+
+    /// The gas price in Wei for the transaction.
+    #[serde(with = "ethnum::serde::permissive")]
+    pub gas_price: U256,
+
+    /// The gas limit for the transaction.
+    #[serde(with = "ethnum::serde::permissive")]
+    pub gas: U256,
+
+    /// The target address for the transaction. This can also be `None` to
+    /// indicate a contract creation transaction.
+    pub to: Option<Address>,
+
+    /// The amount of Ether to send with the transaction.
+    #[serde(with = "ethnum::serde::permissive")]
+    pub value: U256,
+
+    /// The calldata to use for the transaction.
+    #[serde(with = "serialization::bytes")]
+    pub data: Vec<u8>,
+}
+
+impl TxLegacy {
+    // Sign with a wallet.
+    pub fn sign_with_wallet(&mut self, wallet: &Wallet) -> Result<Vec<u8>> {
+        let message = self.get_unsigned_rlp_encoded();
+        let signature = wallet.sign(message)?;
+        let encoded = self.get_signed_rlp_encoded(signature);
+
+        Ok(encoded)
+    }
+
+    /// Returns the RLP encoded transaction without signature.
+    pub fn get_unsigned_rlp_encoded(&self) -> [u8; 32] {
+        // EIP-155: the chain ID is folded into the signing payload as
+        // `[..., chainId, 0, 0]` in place of the `[v, r, s]` tail.
+        let fields = [
+            rlp::uint(self.nonce),
+            rlp::uint(self.gas_price),
+            rlp::uint(self.gas),
+            self.to
+                .map_or_else(|| rlp::bytes(b""), |to| rlp::bytes(&*to)),
+            rlp::uint(self.value),
+            rlp::bytes(&self.data),
+            rlp::uint(self.chain_id),
+            rlp::uint(U256::ZERO),
+            rlp::uint(U256::ZERO),
+        ];
+
+        hash::keccak256(rlp::iter(fields.iter()))
+    }
+
+    /// Returns 32-byte message used for signing.
+    pub fn get_signed_rlp_encoded(&self, signature: Signature) -> Vec<u8> {
+        self.rlp_encode(Some(signature))
+    }
+
+    /// Recovers the address that produced `signature` over this transaction.
+    pub fn recover_signer(&self, signature: &Signature) -> Result<Address> {
+        crate::utils::ecrecover(
+            self.get_unsigned_rlp_encoded(),
+            signature.r(),
+            signature.s(),
+            signature.y_parity(),
+        )
+        .map(|address| Address(address.0))
+    }
+
+    /// Returns the RLP encoded transaction with an optional signature.
+    pub fn rlp_encode(&self, signature: Option<Signature>) -> Vec<u8> {
+        let fields = [
+            rlp::uint(self.nonce),
+            rlp::uint(self.gas_price),
+            rlp::uint(self.gas),
+            self.to
+                .map_or_else(|| rlp::bytes(b""), |to| rlp::bytes(&*to)),
+            rlp::uint(self.value),
+            rlp::bytes(&self.data),
+        ];
+
+        let tail = signature.map(|signature| {
+            // EIP-155: `v = chainId * 2 + 35 + yParity`.
+            let v = self.chain_id * 2 + 35 + signature.y_parity();
+            [rlp::uint(v), rlp::uint(signature.r()), rlp::uint(signature.s())]
+        });
+
+        // Legacy transactions have no EIP-2718 type prefix.
+        rlp::iter(fields.iter().chain(tail.iter().flatten()))
+    }
+
+    /// Inverts [`TxLegacy::rlp_encode`], parsing a raw legacy transaction
+    /// (no EIP-2718 type prefix) back into its fields and signature.
+    pub fn rlp_decode(data: &[u8]) -> Result<(Self, Option<Signature>)> {
+        let items = rlp::decode_list(data)?;
+        let (fields, signature, chain_id) = match items.len() {
+            6 => (items, None, U256::ZERO),
+            9 => {
+                let (fields, tail) = items.split_at(6);
+                let v = rlp::decode_uint(&tail[0])?;
+                let r = rlp::decode_uint(&tail[1])?;
+                let s = rlp::decode_uint(&tail[2])?;
+
+                // Invert `v = chainId * 2 + 35 + yParity` (EIP-155), falling
+                // back to the pre-EIP-155 `v = 27 + yParity` form.
+                let (chain_id, y_parity) = if v >= 35 {
+                    ((v - 35) / 2, (v - 35) % 2)
+                } else if v == 27 || v == 28 {
+                    (U256::ZERO, v - 27)
+                } else {
+                    bail!("invalid legacy transaction `v` value: {v}");
+                };
+
+                (
+                    fields.to_vec(),
+                    Some(Signature::new(y_parity, r, s)),
+                    chain_id,
+                )
+            }
+            n => bail!("unexpected number of RLP fields for a legacy transaction: {n}"),
+        };
+
+        let tx = Self {
+            chain_id,
+            nonce: rlp::decode_uint(&fields[0])?,
+            gas_price: rlp::decode_uint(&fields[1])?,
+            gas: rlp::decode_uint(&fields[2])?,
+            to: rlp::decode_address(&fields[3])?,
+            value: rlp::decode_uint(&fields[4])?,
+            data: fields[5].as_bytes()?.to_vec(),
+        };
+
+        Ok((tx, signature))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wallet::Wallet;
+    use ethaddr::address;
+    use ethnum::AsU256 as _;
+    use hex_literal::hex;
+
+    const DETERMINISTIC_PRIVATE_KEY: [u8; 32] =
+        hex!("4f3edf983ac636a65a842ce7c78d9aa706d3b113bce9c46f30d7d21715b23b1d");
+
+    fn sample() -> TxLegacy {
+        TxLegacy {
+            chain_id: 1.as_u256(),
+            nonce: 66.as_u256(),
+            gas_price: 42e9.as_u256(),
+            gas: 30_000.as_u256(),
+            to: Some(address!("0xDeaDbeefdEAdbeefdEadbEEFdeadbeEFdEaDbeeF")),
+            value: 13.37e18.as_u256(),
+            data: vec![],
+        }
+    }
+
+    #[test]
+    fn decode_round_trips_with_encode() {
+        let tx = sample();
+
+        // The unsigned encoding omits `chain_id` (EIP-155 only folds it into
+        // `v` once signed), so it always decodes back as zero.
+        let (decoded, signature) = TxLegacy::rlp_decode(&tx.rlp_encode(None)).unwrap();
+        assert_eq!(
+            decoded,
+            TxLegacy {
+                chain_id: U256::ZERO,
+                ..tx.clone()
+            }
+        );
+        assert_eq!(signature, None);
+
+        let wallet = Wallet::from_secret(DETERMINISTIC_PRIVATE_KEY).unwrap();
+        let signature = wallet.sign(tx.get_unsigned_rlp_encoded()).unwrap();
+        let (decoded, decoded_signature) =
+            TxLegacy::rlp_decode(&tx.get_signed_rlp_encoded(signature)).unwrap();
+        assert_eq!(decoded, tx);
+        assert_eq!(decoded_signature, Some(signature));
+    }
+
+    #[test]
+    fn sign_then_recover_signer_round_trips() {
+        let tx = sample();
+        let wallet = Wallet::from_secret(DETERMINISTIC_PRIVATE_KEY).unwrap();
+        let signature = wallet.sign(tx.get_unsigned_rlp_encoded()).unwrap();
+
+        let recovered = tx.recover_signer(&signature).unwrap();
+        let expected = crate::utils::address_from_pk(DETERMINISTIC_PRIVATE_KEY).unwrap();
+        assert_eq!(*recovered, expected.0);
+    }
+
+    #[test]
+    fn rlp_decode_rejects_invalid_v() {
+        // A signed legacy transaction (9 fields) with an out-of-range `v`
+        // that is neither a valid pre- nor post-EIP-155 value.
+        let fields = [
+            rlp::uint(1.as_u256()),
+            rlp::uint(42e9.as_u256()),
+            rlp::uint(30_000.as_u256()),
+            rlp::bytes(&*address!("0xDeaDbeefdEAdbeefdEadbEEFdeadbeEFdEaDbeeF")),
+            rlp::uint(13.37e18.as_u256()),
+            rlp::bytes(b""),
+            rlp::uint(1.as_u256()),
+            rlp::uint(1.as_u256()),
+            rlp::uint(1.as_u256()),
+        ];
+        let encoded = rlp::iter(fields.iter());
+
+        assert!(TxLegacy::rlp_decode(&encoded).is_err());
+    }
+}
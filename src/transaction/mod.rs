@@ -0,0 +1,243 @@
+//! Module defining Ethereum transaction data as well as an RLP encoding
+//! implementation.
+
+pub mod accesslist;
+pub mod eip1559;
+pub mod eip2930;
+pub mod eip4844;
+pub mod legacy;
+pub(crate) mod rlp;
+
+use crate::wallet::{Signature, Wallet};
+use anyhow::{anyhow, Result};
+use ethaddr::Address;
+use serde::de::{self, Deserialize, Deserializer};
+
+pub use eip1559::TxEip1559;
+pub use eip2930::TxEip2930;
+pub use eip4844::TxEip4844;
+pub use legacy::TxLegacy;
+
+/// The EIP-2718 transaction type identifier.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TxType {
+    /// A legacy, pre-EIP-2718 transaction.
+    Legacy,
+    /// An EIP-2930 transaction carrying an access list.
+    Eip2930,
+    /// An EIP-1559 transaction using the fee-market gas model.
+    Eip1559,
+    /// An EIP-4844 blob-carrying transaction.
+    Eip4844,
+}
+
+/// A typed Ethereum transaction, as defined by EIP-2718.
+///
+/// This wraps the per-type transaction structs and dispatches RLP encoding
+/// and signing to whichever variant is active.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TypedTransaction {
+    /// A legacy transaction.
+    Legacy(TxLegacy),
+    /// An EIP-2930 transaction.
+    Eip2930(TxEip2930),
+    /// An EIP-1559 transaction.
+    Eip1559(TxEip1559),
+    /// An EIP-4844 blob transaction.
+    Eip4844(TxEip4844),
+}
+
+impl TypedTransaction {
+    /// Returns the EIP-2718 type of this transaction.
+    pub fn tx_type(&self) -> TxType {
+        match self {
+            Self::Legacy(_) => TxType::Legacy,
+            Self::Eip2930(_) => TxType::Eip2930,
+            Self::Eip1559(_) => TxType::Eip1559,
+            Self::Eip4844(_) => TxType::Eip4844,
+        }
+    }
+
+    // Sign with a wallet.
+    pub fn sign_with_wallet(&mut self, wallet: &Wallet) -> Result<Vec<u8>> {
+        match self {
+            Self::Legacy(tx) => tx.sign_with_wallet(wallet),
+            Self::Eip2930(tx) => tx.sign_with_wallet(wallet),
+            Self::Eip1559(tx) => tx.sign_with_wallet(wallet),
+            Self::Eip4844(tx) => tx.sign_with_wallet(wallet),
+        }
+    }
+
+    /// Returns the RLP encoded transaction without signature.
+    pub fn get_unsigned_rlp_encoded(&self) -> [u8; 32] {
+        match self {
+            Self::Legacy(tx) => tx.get_unsigned_rlp_encoded(),
+            Self::Eip2930(tx) => tx.get_unsigned_rlp_encoded(),
+            Self::Eip1559(tx) => tx.get_unsigned_rlp_encoded(),
+            Self::Eip4844(tx) => tx.get_unsigned_rlp_encoded(),
+        }
+    }
+
+    /// Returns 32-byte message used for signing.
+    pub fn get_signed_rlp_encoded(&self, signature: Signature) -> Vec<u8> {
+        match self {
+            Self::Legacy(tx) => tx.get_signed_rlp_encoded(signature),
+            Self::Eip2930(tx) => tx.get_signed_rlp_encoded(signature),
+            Self::Eip1559(tx) => tx.get_signed_rlp_encoded(signature),
+            Self::Eip4844(tx) => tx.get_signed_rlp_encoded(signature),
+        }
+    }
+
+    /// Returns the RLP encoded transaction with an optional signature.
+    pub fn rlp_encode(&self, signature: Option<Signature>) -> Vec<u8> {
+        match self {
+            Self::Legacy(tx) => tx.rlp_encode(signature),
+            Self::Eip2930(tx) => tx.rlp_encode(signature),
+            Self::Eip1559(tx) => tx.rlp_encode(signature),
+            Self::Eip4844(tx) => tx.rlp_encode(signature),
+        }
+    }
+
+    /// Recovers the address that produced `signature` over this transaction.
+    pub fn recover_signer(&self, signature: &Signature) -> Result<Address> {
+        match self {
+            Self::Legacy(tx) => tx.recover_signer(signature),
+            Self::Eip2930(tx) => tx.recover_signer(signature),
+            Self::Eip1559(tx) => tx.recover_signer(signature),
+            Self::Eip4844(tx) => tx.recover_signer(signature),
+        }
+    }
+
+    /// Parses a raw signed or unsigned transaction off the wire, picking the
+    /// variant based on its EIP-2718 type byte (or the absence of one, for
+    /// legacy transactions).
+    pub fn rlp_decode(data: &[u8]) -> Result<(Self, Option<Signature>)> {
+        match data.first() {
+            Some(0x01) => {
+                let (tx, signature) = TxEip2930::rlp_decode(data)?;
+                Ok((Self::Eip2930(tx), signature))
+            }
+            Some(0x02) => {
+                let (tx, signature) = TxEip1559::rlp_decode(data)?;
+                Ok((Self::Eip1559(tx), signature))
+            }
+            Some(0x03) => {
+                let (tx, signature) = TxEip4844::rlp_decode(data)?;
+                Ok((Self::Eip4844(tx), signature))
+            }
+            Some(&prefix) if prefix >= 0xc0 => {
+                let (tx, signature) = TxLegacy::rlp_decode(data)?;
+                Ok((Self::Legacy(tx), signature))
+            }
+            _ => Err(anyhow!("unrecognized transaction envelope")),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for TypedTransaction {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        // The `type` field (EIP-2718) picks which variant to deserialize
+        // into; transactions without one are treated as legacy.
+        let value = serde_json::Value::deserialize(deserializer)?;
+        let tx_type = match value.get("type") {
+            Some(serde_json::Value::String(s)) => {
+                u64::from_str_radix(s.trim_start_matches("0x"), 16)
+                    .map_err(|_| de::Error::custom(format!("invalid transaction type {s:?}")))?
+            }
+            Some(serde_json::Value::Number(n)) => n
+                .as_u64()
+                .ok_or_else(|| de::Error::custom("invalid transaction type"))?,
+            Some(other) => {
+                return Err(de::Error::custom(format!("invalid transaction type {other:?}")))
+            }
+            None => 0,
+        };
+
+        match tx_type {
+            0 => Ok(Self::Legacy(
+                serde_json::from_value(value).map_err(de::Error::custom)?,
+            )),
+            1 => Ok(Self::Eip2930(
+                serde_json::from_value(value).map_err(de::Error::custom)?,
+            )),
+            2 => Ok(Self::Eip1559(
+                serde_json::from_value(value).map_err(de::Error::custom)?,
+            )),
+            3 => Ok(Self::Eip4844(
+                serde_json::from_value(value).map_err(de::Error::custom)?,
+            )),
+            other => Err(de::Error::custom(format!(
+                "unsupported transaction type {other:#x}"
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn rlp_decode_dispatches_by_type_byte() {
+        let (tx, _) = TypedTransaction::rlp_decode(&TxLegacy::default().rlp_encode(None)).unwrap();
+        assert!(matches!(tx, TypedTransaction::Legacy(_)));
+
+        let (tx, _) =
+            TypedTransaction::rlp_decode(&TxEip2930::default().rlp_encode(None)).unwrap();
+        assert!(matches!(tx, TypedTransaction::Eip2930(_)));
+
+        let (tx, _) =
+            TypedTransaction::rlp_decode(&TxEip1559::default().rlp_encode(None)).unwrap();
+        assert!(matches!(tx, TypedTransaction::Eip1559(_)));
+
+        let (tx, _) =
+            TypedTransaction::rlp_decode(&TxEip4844::default().rlp_encode(None)).unwrap();
+        assert!(matches!(tx, TypedTransaction::Eip4844(_)));
+    }
+
+    #[test]
+    fn deserialize_picks_variant_by_type_field() {
+        let legacy = json!({
+            "chainId": 1,
+            "nonce": 0,
+            "gasPrice": 0,
+            "gas": 21000,
+            "value": 0,
+            "data": "0x",
+        });
+        let tx: TypedTransaction = serde_json::from_value(legacy).unwrap();
+        assert!(matches!(tx, TypedTransaction::Legacy(_)));
+
+        let eip2930 = json!({
+            "type": "0x1",
+            "chainId": 1,
+            "nonce": 0,
+            "gasPrice": 0,
+            "gas": 21000,
+            "value": 0,
+            "data": "0x",
+        });
+        let tx: TypedTransaction = serde_json::from_value(eip2930).unwrap();
+        assert!(matches!(tx, TypedTransaction::Eip2930(_)));
+
+        let eip1559 = json!({
+            "type": 2,
+            "chainId": 1,
+            "nonce": 0,
+            "maxPriorityFeePerGas": 0,
+            "maxFeePerGas": 0,
+            "gas": 21000,
+            "value": 0,
+            "data": "0x",
+        });
+        let tx: TypedTransaction = serde_json::from_value(eip1559).unwrap();
+        assert!(matches!(tx, TypedTransaction::Eip1559(_)));
+
+        let unsupported = json!({"type": "0x99"});
+        assert!(serde_json::from_value::<TypedTransaction>(unsupported).is_err());
+    }
+}
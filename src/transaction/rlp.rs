@@ -0,0 +1,188 @@
+//! Minimal RLP (Recursive Length Prefix) encoding and decoding primitives
+//! used to encode and parse Ethereum transactions.
+
+use anyhow::{anyhow, bail, Result};
+use ethaddr::Address;
+use ethnum::U256;
+
+/// RLP-encodes a byte string.
+pub fn bytes(data: &[u8]) -> Vec<u8> {
+    if data.len() == 1 && data[0] < 0x80 {
+        return data.to_vec();
+    }
+
+    let mut encoded = encode_length(data.len(), 0x80);
+    encoded.extend_from_slice(data);
+    encoded
+}
+
+/// RLP-encodes an unsigned integer as its minimal big-endian byte string.
+pub fn uint(value: U256) -> Vec<u8> {
+    let be = value.to_be_bytes();
+    let first_nonzero = be.iter().position(|&byte| byte != 0).unwrap_or(be.len());
+    bytes(&be[first_nonzero..])
+}
+
+/// RLP-encodes a list from an iterator of already RLP-encoded items.
+pub fn iter<'a>(items: impl Iterator<Item = &'a Vec<u8>>) -> Vec<u8> {
+    let payload: Vec<u8> = items.flat_map(|item| item.iter().copied()).collect();
+    let mut encoded = encode_length(payload.len(), 0xc0);
+    encoded.extend(payload);
+    encoded
+}
+
+/// Encodes the RLP length prefix for a string (`offset` `0x80`) or list
+/// (`offset` `0xc0`) of the given payload length.
+fn encode_length(len: usize, offset: u8) -> Vec<u8> {
+    if len < 56 {
+        return vec![offset + len as u8];
+    }
+
+    let len_be = (len as u64).to_be_bytes();
+    let first_nonzero = len_be
+        .iter()
+        .position(|&byte| byte != 0)
+        .unwrap_or(len_be.len() - 1);
+    let len_bytes = &len_be[first_nonzero..];
+
+    let mut encoded = vec![offset + 55 + len_bytes.len() as u8];
+    encoded.extend_from_slice(len_bytes);
+    encoded
+}
+
+/// A decoded RLP item: either a byte string or a list of items.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Item {
+    String(Vec<u8>),
+    List(Vec<Item>),
+}
+
+impl Item {
+    /// Borrows the item as a byte string, erroring if it is a list.
+    pub fn as_bytes(&self) -> Result<&[u8]> {
+        match self {
+            Self::String(bytes) => Ok(bytes),
+            Self::List(_) => Err(anyhow!("expected an RLP string, found a list")),
+        }
+    }
+
+    /// Consumes the item as a byte string, erroring if it is a list.
+    pub fn into_bytes(self) -> Result<Vec<u8>> {
+        match self {
+            Self::String(bytes) => Ok(bytes),
+            Self::List(_) => Err(anyhow!("expected an RLP string, found a list")),
+        }
+    }
+
+    /// Consumes the item as a list, erroring if it is a string.
+    pub fn into_list(self) -> Result<Vec<Item>> {
+        match self {
+            Self::List(items) => Ok(items),
+            Self::String(_) => Err(anyhow!("expected an RLP list, found a string")),
+        }
+    }
+}
+
+/// Decodes the unsigned big-endian integer encoded in an RLP string,
+/// rejecting non-minimal encodings (leading zero bytes).
+pub fn decode_uint(item: &Item) -> Result<U256> {
+    let bytes = item.as_bytes()?;
+    if !bytes.is_empty() && bytes[0] == 0 {
+        bail!("invalid RLP integer: non-minimal encoding");
+    }
+    if bytes.len() > 32 {
+        bail!("invalid RLP integer: too large");
+    }
+
+    let mut buf = [0u8; 32];
+    buf[32 - bytes.len()..].copy_from_slice(bytes);
+    Ok(U256::from_be_bytes(buf))
+}
+
+/// Decodes an address from an RLP string: a 20-byte address, or an empty
+/// string for a contract creation transaction.
+pub fn decode_address(item: &Item) -> Result<Option<Address>> {
+    let bytes = item.as_bytes()?;
+    match bytes.len() {
+        0 => Ok(None),
+        20 => {
+            let array: [u8; 20] = bytes.try_into().expect("length checked above");
+            Ok(Some(Address(array)))
+        }
+        len => bail!("invalid address length: {len}"),
+    }
+}
+
+/// Decodes a single RLP item from the front of `data`, returning the item
+/// and the remaining, unconsumed bytes.
+pub fn decode(data: &[u8]) -> Result<(Item, &[u8])> {
+    let (&prefix, rest) = data
+        .split_first()
+        .ok_or_else(|| anyhow!("unexpected end of RLP data"))?;
+
+    match prefix {
+        0x00..=0x7f => Ok((Item::String(vec![prefix]), rest)),
+        0x80..=0xb7 => {
+            let len = (prefix - 0x80) as usize;
+            let (payload, rest) = take(rest, len)?;
+            Ok((Item::String(payload.to_vec()), rest))
+        }
+        0xb8..=0xbf => {
+            let len_len = (prefix - 0xb7) as usize;
+            let (len_bytes, rest) = take(rest, len_len)?;
+            let (payload, rest) = take(rest, decode_length(len_bytes)?)?;
+            Ok((Item::String(payload.to_vec()), rest))
+        }
+        0xc0..=0xf7 => {
+            let len = (prefix - 0xc0) as usize;
+            let (payload, rest) = take(rest, len)?;
+            Ok((Item::List(decode_items(payload)?), rest))
+        }
+        0xf8..=0xff => {
+            let len_len = (prefix - 0xf7) as usize;
+            let (len_bytes, rest) = take(rest, len_len)?;
+            let (payload, rest) = take(rest, decode_length(len_bytes)?)?;
+            Ok((Item::List(decode_items(payload)?), rest))
+        }
+    }
+}
+
+/// Decodes a complete buffer as a single RLP list, erroring on trailing
+/// bytes.
+pub fn decode_list(data: &[u8]) -> Result<Vec<Item>> {
+    let (item, rest) = decode(data)?;
+    if !rest.is_empty() {
+        bail!("unexpected trailing bytes after RLP list");
+    }
+    item.into_list()
+}
+
+fn take(data: &[u8], len: usize) -> Result<(&[u8], &[u8])> {
+    if data.len() < len {
+        bail!("unexpected end of RLP data");
+    }
+    Ok(data.split_at(len))
+}
+
+fn decode_length(len_bytes: &[u8]) -> Result<usize> {
+    if len_bytes.is_empty() || len_bytes[0] == 0 {
+        bail!("invalid RLP length encoding");
+    }
+    if len_bytes.len() > 8 {
+        bail!("RLP length too large");
+    }
+
+    let mut buf = [0u8; 8];
+    buf[8 - len_bytes.len()..].copy_from_slice(len_bytes);
+    Ok(u64::from_be_bytes(buf) as usize)
+}
+
+fn decode_items(mut data: &[u8]) -> Result<Vec<Item>> {
+    let mut items = Vec::new();
+    while !data.is_empty() {
+        let (item, rest) = decode(data)?;
+        items.push(item);
+        data = rest;
+    }
+    Ok(items)
+}
@@ -1,5 +1,7 @@
-use anyhow::Result;
+use anyhow::{anyhow, bail, Result};
 use ethereum_types::H160 as Address;
+use ethnum::U256;
+use k256::ecdsa::{RecoveryId, Signature as RecoverableSignature, VerifyingKey};
 use k256::elliptic_curve::sec1::ToEncodedPoint;
 use k256::SecretKey;
 use rand::rngs::StdRng;
@@ -46,3 +48,75 @@ where
     let hash = hash::keccak256(&public_key[1..]);
     Ok(Address::from_slice(&hash[12..]))
 }
+
+/// Recovers the Ethereum address that produced an ECDSA signature over
+/// `message_hash`, mirroring the logic in [`address_from_pk`].
+///
+/// Rejects signatures with a malleable (high) `s` value, per EIP-2.
+pub fn ecrecover(message_hash: [u8; 32], r: U256, s: U256, y_parity: U256) -> Result<Address> {
+    if s > secp256k1_half_order() {
+        bail!("signature `s` value is malleable, expected low-s form");
+    }
+
+    let mut signature_bytes = [0u8; 64];
+    signature_bytes[..32].copy_from_slice(&r.to_be_bytes());
+    signature_bytes[32..].copy_from_slice(&s.to_be_bytes());
+    let signature = RecoverableSignature::from_slice(&signature_bytes)?;
+
+    let recovery_byte = if y_parity == U256::ZERO { 0 } else { 1 };
+    let recovery_id = RecoveryId::from_byte(recovery_byte)
+        .ok_or_else(|| anyhow!("invalid recovery id: {y_parity}"))?;
+
+    let verifying_key =
+        VerifyingKey::recover_from_prehash(&message_hash, &signature, recovery_id)?;
+    let public_key: [u8; 65] = verifying_key
+        .to_encoded_point(false)
+        .as_bytes()
+        .try_into()?;
+    debug_assert_eq!(public_key[0], 0x04);
+    let hash = hash::keccak256(&public_key[1..]);
+    Ok(Address::from_slice(&hash[12..]))
+}
+
+/// The secp256k1 curve order, halved, used to reject malleable signatures.
+fn secp256k1_half_order() -> U256 {
+    U256::from_be_bytes(hex_literal::hex!(
+        "7FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF5D576E7357A4501DDFE92F46681B20A0"
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ethnum::AsU256 as _;
+    use k256::ecdsa::SigningKey;
+
+    const DETERMINISTIC_PRIVATE_KEY: [u8; 32] =
+        hex_literal::hex!("4f3edf983ac636a65a842ce7c78d9aa706d3b113bce9c46f30d7d21715b23b1d");
+
+    #[test]
+    fn ecrecover_recovers_the_signing_address() {
+        let message_hash = hash::keccak256(b"hello ecrecover");
+        let signing_key = SigningKey::from_bytes((&DETERMINISTIC_PRIVATE_KEY).into()).unwrap();
+        let (signature, recovery_id) = signing_key
+            .sign_prehash_recoverable(&message_hash)
+            .unwrap();
+
+        let r = U256::from_be_bytes(signature.r().to_bytes().into());
+        let s = U256::from_be_bytes(signature.s().to_bytes().into());
+        let y_parity = recovery_id.to_byte().as_u256();
+
+        let recovered = ecrecover(message_hash, r, s, y_parity).unwrap();
+        let expected = address_from_pk(DETERMINISTIC_PRIVATE_KEY).unwrap();
+        assert_eq!(recovered, expected);
+    }
+
+    #[test]
+    fn ecrecover_rejects_malleable_high_s_signatures() {
+        let message_hash = [0u8; 32];
+        let r = 1u8.as_u256();
+        let s = secp256k1_half_order() + 1u8.as_u256();
+
+        assert!(ecrecover(message_hash, r, s, U256::ZERO).is_err());
+    }
+}